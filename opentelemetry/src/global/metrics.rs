@@ -1,41 +1,149 @@
 use crate::metrics::{self, Meter, MeterProvider};
 use crate::{otel_error, otel_info, InstrumentationScope};
+use std::borrow::Cow;
+use std::fmt;
 use std::sync::{Arc, OnceLock, RwLock};
 
-type GlobalMeterProvider = Arc<dyn MeterProvider + Send + Sync>;
+/// A cloneable handle to the globally configured [`MeterProvider`].
+///
+/// Returned by [`meter_provider`]; `meter`/`meter_with_scope` calls are
+/// forwarded to the wrapped provider.
+#[derive(Clone)]
+pub struct GlobalMeterProvider {
+    provider: Arc<dyn MeterProvider + Send + Sync>,
+}
+
+impl fmt::Debug for GlobalMeterProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GlobalMeterProvider").finish()
+    }
+}
+
+impl GlobalMeterProvider {
+    /// Create a new global meter provider
+    pub fn new<P>(provider: P) -> Self
+    where
+        P: metrics::MeterProvider + Send + Sync + 'static,
+    {
+        GlobalMeterProvider {
+            provider: Arc::new(provider),
+        }
+    }
+}
+
+impl metrics::MeterProvider for GlobalMeterProvider {
+    fn meter(&self, name: &'static str) -> Meter {
+        self.provider.meter(name)
+    }
+
+    fn meter_with_scope(&self, scope: InstrumentationScope) -> Meter {
+        self.provider.meter_with_scope(scope)
+    }
+}
 
 /// The global `MeterProvider` singleton.
 static GLOBAL_METER_PROVIDER: OnceLock<RwLock<GlobalMeterProvider>> = OnceLock::new();
 
 #[inline]
 fn global_meter_provider() -> &'static RwLock<GlobalMeterProvider> {
-    GLOBAL_METER_PROVIDER
-        .get_or_init(|| RwLock::new(Arc::new(crate::metrics::noop::NoopMeterProvider::new())))
+    GLOBAL_METER_PROVIDER.get_or_init(|| {
+        RwLock::new(GlobalMeterProvider::new(
+            crate::metrics::noop::NoopMeterProvider::new(),
+        ))
+    })
+}
+
+/// Error returned when the global [`MeterProvider`] could not be installed
+/// because the lock guarding it was poisoned by a panicking thread.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SetMeterProviderError {
+    _private: (),
+}
+
+impl fmt::Display for SetMeterProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not set the global meter provider: the RwLock guarding it was poisoned"
+        )
+    }
+}
+
+impl std::error::Error for SetMeterProviderError {}
+
+/// Swaps in `new_provider` as the global meter provider, returning whatever
+/// was previously installed. Does not log; callers log with wording that
+/// matches what they're actually doing (installing vs. disabling).
+fn install_meter_provider(
+    new_provider: GlobalMeterProvider,
+) -> Result<GlobalMeterProvider, SetMeterProviderError> {
+    let mut global_provider = global_meter_provider().write();
+
+    if let Ok(ref mut provider) = global_provider {
+        Ok(std::mem::replace(&mut **provider, new_provider))
+    } else {
+        Err(SetMeterProviderError { _private: () })
+    }
 }
 
 /// Sets the given [`MeterProvider`] instance as the current global meter
-/// provider.
-pub fn set_meter_provider<P>(new_provider: P)
+/// provider, returning the previously installed provider on success.
+///
+/// # Errors
+///
+/// Returns [`SetMeterProviderError`] if the lock guarding the global
+/// provider was poisoned.
+pub fn set_meter_provider<P>(new_provider: P) -> Result<GlobalMeterProvider, SetMeterProviderError>
 where
     P: metrics::MeterProvider + Send + Sync + 'static,
 {
-    // Try to set the global meter provider. If the RwLock is poisoned, we'll log an error.
-    let mut global_provider = global_meter_provider().write();
+    match install_meter_provider(GlobalMeterProvider::new(new_provider)) {
+        Ok(previous_provider) => {
+            otel_info!(name: "MeterProvider.GlobalSet", message = "Global meter provider is set. Meters can now be created using global::meter(), global::meter_with_name(), or global::meter_with_scope().");
+            Ok(previous_provider)
+        }
+        Err(err) => {
+            otel_error!(name: "MeterProvider.GlobalSetFailed", message = "Global meter provider is not set due to lock poison. Meters created using global::meter(), global::meter_with_name(), or global::meter_with_scope() will not function.");
+            Err(err)
+        }
+    }
+}
 
-    if let Ok(ref mut provider) = global_provider {
-        **provider = Arc::new(new_provider);
-        otel_info!(name: "MeterProvider.GlobalSet", message = "Global meter provider is set. Meters can now be created using global::meter() or global::meter_with_scope().");
-    } else {
-        otel_error!(name: "MeterProvider.GlobalSetFailed", message = "Global meter provider is not set due to lock poison. Meters created using global::meter() or global::meter_with_scope() will not function.");
+/// Resets the global [`MeterProvider`] to a no-op provider, returning the
+/// provider that was uninstalled.
+///
+/// # Errors
+///
+/// Returns [`SetMeterProviderError`] under the same conditions as
+/// [`set_meter_provider`].
+pub fn disable_meter_provider() -> Result<GlobalMeterProvider, SetMeterProviderError> {
+    let noop = GlobalMeterProvider::new(crate::metrics::noop::NoopMeterProvider::new());
+
+    match install_meter_provider(noop) {
+        Ok(previous_provider) => {
+            otel_info!(name: "MeterProvider.GlobalDisabled", message = "Global meter provider is disabled and reset to a no-op provider.");
+            Ok(previous_provider)
+        }
+        Err(err) => {
+            otel_error!(name: "MeterProvider.GlobalDisableFailed", message = "Global meter provider could not be disabled due to lock poison. Meters created using global::meter(), global::meter_with_name(), or global::meter_with_scope() fall back to a no-op provider while the lock is poisoned.");
+            Err(err)
+        }
     }
 }
 
 /// Returns an instance of the currently configured global [`MeterProvider`].
+///
+/// Falls back to a no-op provider if the lock guarding the global provider
+/// was poisoned, rather than panicking.
 pub fn meter_provider() -> GlobalMeterProvider {
-    global_meter_provider()
-        .read()
-        .expect("GLOBAL_METER_PROVIDER RwLock poisoned")
-        .clone()
+    match global_meter_provider().read() {
+        Ok(provider) => provider.clone(),
+        Err(_) => {
+            otel_error!(name: "MeterProvider.GlobalGetFailed", message = "Reading the global meter provider failed due to lock poison; falling back to a no-op MeterProvider. Meters created using global::meter(), global::meter_with_name(), or global::meter_with_scope() will not function.");
+            GlobalMeterProvider::new(crate::metrics::noop::NoopMeterProvider::new())
+        }
+    }
 }
 
 /// Creates a named [`Meter`] via the currently configured global [`MeterProvider`].
@@ -47,6 +155,18 @@ pub fn meter(name: &'static str) -> Meter {
     meter_provider().meter(name)
 }
 
+/// Creates a named [`Meter`] via the currently configured global
+/// [`MeterProvider`], accepting any name convertible to `Cow<'static, str>`.
+///
+/// Unlike [`meter`], this doesn't require `&'static str`, so an owned
+/// `String` computed at runtime can be passed directly. This builds an
+/// [`InstrumentationScope`] and goes through [`meter_with_scope`]; the
+/// [`MeterProvider`] trait's own `meter` method is unchanged and still takes
+/// `&'static str`, which [`meter`] remains the zero-cost entry point for.
+pub fn meter_with_name(name: impl Into<Cow<'static, str>>) -> Meter {
+    meter_provider().meter_with_scope(InstrumentationScope::builder(name).build())
+}
+
 /// Creates a [`Meter`] with the given instrumentation scope.
 ///
 /// This is a simpler alternative to `global::meter_provider().meter_with_scope(...)`
@@ -70,3 +190,100 @@ pub fn meter(name: &'static str) -> Meter {
 pub fn meter_with_scope(scope: InstrumentationScope) -> Meter {
     meter_provider().meter_with_scope(scope)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    // Serializes tests that mutate the process-global meter provider.
+    static TEST_MUTEX: Mutex<()> = Mutex::new(());
+
+    #[derive(Debug)]
+    struct CountingMeterProvider {
+        meter_count: Arc<AtomicUsize>,
+    }
+
+    impl metrics::MeterProvider for CountingMeterProvider {
+        fn meter_with_scope(&self, scope: InstrumentationScope) -> Meter {
+            self.meter_count.fetch_add(1, Ordering::SeqCst);
+            crate::metrics::noop::NoopMeterProvider::new().meter_with_scope(scope)
+        }
+    }
+
+    #[test]
+    fn set_meter_provider_returns_previously_installed_provider() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let first_count = Arc::new(AtomicUsize::new(0));
+        let second_count = Arc::new(AtomicUsize::new(0));
+
+        set_meter_provider(CountingMeterProvider {
+            meter_count: first_count.clone(),
+        })
+        .expect("first install should succeed");
+
+        let previous = set_meter_provider(CountingMeterProvider {
+            meter_count: second_count.clone(),
+        })
+        .expect("second install should succeed");
+
+        // The value handed back must be the *first* provider, not the second.
+        previous.meter("probe");
+        assert_eq!(first_count.load(Ordering::SeqCst), 1);
+        assert_eq!(second_count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn disable_meter_provider_resets_to_noop_and_returns_installed_provider() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        set_meter_provider(CountingMeterProvider {
+            meter_count: count.clone(),
+        })
+        .expect("install should succeed");
+
+        let previous = disable_meter_provider().expect("disable should succeed");
+        previous.meter("probe");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        // global::meter() must now be served by the no-op provider we just
+        // reset to, so it must not touch the counter we were tracking.
+        let _ = meter("after-disable");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn global_meter_provider_forwards_to_inner_provider() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let provider = GlobalMeterProvider::new(CountingMeterProvider {
+            meter_count: count.clone(),
+        });
+
+        let _ = provider.meter("via-meter");
+        let _ = provider.meter_with_scope(InstrumentationScope::builder("via-scope").build());
+
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn meter_with_name_threads_an_owned_string_through_instrumentation_scope() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        set_meter_provider(CountingMeterProvider {
+            meter_count: count.clone(),
+        })
+        .expect("install should succeed");
+
+        // Build the name at runtime, e.g. as a library reading a plugin id.
+        let owned_name = format!("{}-{}", "plugin", 1);
+        let _ = meter_with_name(owned_name);
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}